@@ -2,26 +2,44 @@ use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
 
+use crate::libs::math::statistics::Statistics;
+
+/// Per-column statistics captured by `DataSet::normalize`, kept around so the same
+/// transform can be inverted with `denormalize` or applied to new data with
+/// `normalize_with`.
+#[derive(Debug, Clone)]
+pub struct DataSetNormalization {
+    /// One `Statistics` per feature column, in column order.
+    pub feature_stats: Vec<Statistics>,
+    /// `Statistics` for the output column.
+    pub output_stats: Statistics,
+}
+
 /// Represents a dataset containing input and output values.
 ///
 /// This structure is used to store and manage collections of paired input and
 /// output data points, typically for tasks like model training and evaluation.
-#[derive(Debug)] // Enable printing for debugging
+#[derive(Debug, Clone)] // Enable printing for debugging and cheap copies for sweeps like `tune_regularization`
 pub struct DataSet {
-    /// A vector storing the input values of the dataset.
-    pub input: Vec<f64>,
+    /// A vector storing the feature vectors of the dataset, one row per data point.
+    pub input: Vec<Vec<f64>>,
     /// A vector storing the corresponding output values of the dataset.
     pub output: Vec<f64>,
     /// The number of data points in the dataset.
     pub size: usize,
+    /// The number of features in each row of `input`.
+    pub n_features: usize,
+    /// The statistics used by the most recent `normalize()` or `normalize_with()` call, if any.
+    pub normalization: Option<DataSetNormalization>,
 }
 
 impl DataSet {
     /// Creates a new DataSet from a file.
     ///
     /// Reads lines from the specified file, parses them into input and output
-    /// values, and constructs a DataSet instance. Handles potential errors during
-    /// file reading and parsing.
+    /// values, and constructs a DataSet instance. Every line is split on commas;
+    /// all but the last column are read as features and the last column is read
+    /// as the output. Handles potential errors during file reading and parsing.
     ///
     /// # Arguments
     ///
@@ -35,38 +53,60 @@ impl DataSet {
         // Attempt to read lines from the file.
         if let Ok(lines) = DataSet::read_lines(path) {
             // Initialize vectors to store parsed data.
-            let mut input_vec: Vec<f64> = Vec::new();
+            let mut input_vec: Vec<Vec<f64>> = Vec::new();
             let mut output_vec: Vec<f64> = Vec::new();
+            let mut n_features: Option<usize> = None;
 
             // Iterate through each line and parse it.
             for line in lines {
                 if let Ok(ip) = line {
                     // Split the line into comma-separated values.
-                    let line: Vec<&str> = ip.split(",").collect();
+                    let columns: Vec<&str> = ip.split(",").collect();
+
+                    if columns.len() < 2 {
+                        continue; // Skip lines without at least one feature and an output.
+                    }
+
+                    let (feature_columns, output_column) = columns.split_at(columns.len() - 1);
 
-                    // Parse the output value (first element).
-                    let output: f64 = match line[0].parse() {
+                    // Parse the output value (last column).
+                    let output: f64 = match output_column[0].parse() {
                         Ok(num) => num,
                         Err(_) => continue, // Skip invalid lines
                     };
 
-                    // Parse the input value (second element).
-                    let input: f64 = match line[1].parse() {
-                        Ok(num) => num,
-                        Err(_) => continue, // Skip invalid lines
+                    // Parse the feature values (all but the last column).
+                    let features: Option<Vec<f64>> = feature_columns
+                        .iter()
+                        .map(|value| value.parse().ok())
+                        .collect();
+                    let features: Vec<f64> = match features {
+                        Some(features) => features,
+                        None => continue, // Skip invalid lines
                     };
 
-                    // Add parsed values to the vectors.
-                    input_vec.push(input);
+                    // Every row must agree with the first valid row's width; a ragged
+                    // `input` would panic downstream wherever code indexes by `n_features`.
+                    match n_features {
+                        Some(expected) if expected != features.len() => continue,
+                        None => n_features = Some(features.len()),
+                        _ => {}
+                    }
+
+                    input_vec.push(features);
                     output_vec.push(output);
                 }
             }
 
+            let n_features: usize = n_features.unwrap_or(0);
+
             // Return a DataSet with the parsed data.
             return DataSet {
                 size: input_vec.len(),
                 input: input_vec,
                 output: output_vec,
+                n_features,
+                normalization: None,
             };
         }
 
@@ -75,6 +115,8 @@ impl DataSet {
             input: Vec::new(),
             output: Vec::new(),
             size: 0,
+            n_features: 0,
+            normalization: None,
         };
     }
 
@@ -85,17 +127,18 @@ impl DataSet {
     ///
     /// # Arguments
     ///
-    /// * `input`: The input value to add to the DataSet.
+    /// * `input`: The feature vector to add to the DataSet.
     /// * `output`: The corresponding output value to add.
     ///
     /// # Examples
     ///
     /// ```rust
     /// let mut dataset = DataSet::new("data.csv");
-    /// dataset.add_row(2.5, 4.1); // Add a new data point
+    /// dataset.add_row(vec![2.5], 4.1); // Add a new data point
     /// ```
     #[allow(dead_code)]
-    fn add_row(&mut self, input: f64, output: f64) {
+    fn add_row(&mut self, input: Vec<f64>, output: f64) {
+        self.n_features = input.len();
         self.input.push(input);
         self.output.push(output);
         self.size += 1;
@@ -138,6 +181,11 @@ impl DataSet {
 
     /// Normalizes the input and output values of a `DataSet` by subtracting the mean and dividing by the standard deviation.
     ///
+    /// Each feature column is normalized independently using its own `Statistics`. The
+    /// fitted means and standard deviations are stored on `self.normalization` so the
+    /// transform can later be inverted with `denormalize` or applied to new data (e.g.
+    /// a validation split, or a prediction row) with `normalize_with`.
+    ///
     /// # Arguments
     ///
     /// * `self`: A mutable reference to the `DataSet` object.
@@ -149,38 +197,167 @@ impl DataSet {
     /// data.normalize();
     /// ```
     pub fn normalize(&mut self) {
-        // Calculate the mean of the input and output values.
-        let input_mean: f64 = self.input.iter().sum::<f64>() / self.size as f64;
-        let output_mean: f64 = self.output.iter().sum::<f64>() / self.size as f64;
+        let feature_stats: Vec<Statistics> = (0..self.n_features)
+            .map(|feature| {
+                let column: Vec<f64> = self.input.iter().map(|row| row[feature]).collect();
+                Statistics::new(&column)
+            })
+            .collect();
+        let output_stats: Statistics = Statistics::new(&self.output);
 
-        // Calculate the standard deviation of the input and output values.
-        let input_std_dev: f64 = self
-            .input
-            .iter()
-            .map(|x: &f64| (x - input_mean).powf(2.0))
-            .sum::<f64>()
-            .sqrt()
-            / self.size as f64;
-        let output_std_dev: f64 = self
+        self.normalize_with(&DataSetNormalization {
+            feature_stats,
+            output_stats,
+        });
+    }
+
+    /// Applies previously-fitted statistics (typically the training set's) to this
+    /// `DataSet`, so a validation or test split — or a single prediction row via
+    /// `normalize_row` — is normalized against the same scale without leaking its own
+    /// mean and standard deviation into the transform.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats`: The `DataSetNormalization` to apply, e.g. from a training set's
+    ///   `normalize()` call.
+    ///
+    /// # Example usage
+    ///
+    /// ```rust
+    /// let mut train = DataSet::new(...);
+    /// train.normalize();
+    /// let stats = train.normalization.clone().unwrap();
+    ///
+    /// let mut validation = DataSet::new(...);
+    /// validation.normalize_with(&stats);
+    /// ```
+    pub fn normalize_with(&mut self, stats: &DataSetNormalization) {
+        self.output = self
             .output
             .iter()
-            .map(|x: &f64| (x - output_mean).powf(2.0))
-            .sum::<f64>()
-            .sqrt()
-            / self.size as f64;
-
-        // Normalize the input and output values.
-        self.input = self
-            .input
-            .iter()
-            .map(|x: &f64| (x - input_mean) / input_std_dev)
+            .map(|y| (y - stats.output_stats.mean) / stats.output_stats.std_dev)
             .collect();
+
+        for row in self.input.iter_mut() {
+            *row = normalize_row(stats, row);
+        }
+
+        self.normalization = Some(stats.clone());
+    }
+
+    /// Inverts the most recent `normalize()`/`normalize_with()` call, restoring `input`
+    /// and `output` to their original scale. Does nothing if the dataset was never
+    /// normalized.
+    ///
+    /// # Example usage
+    ///
+    /// ```rust
+    /// let mut data = DataSet::new(...);
+    /// data.normalize();
+    /// data.denormalize(); // back to the original units
+    /// ```
+    pub fn denormalize(&mut self) {
+        let stats: DataSetNormalization = match self.normalization.take() {
+            Some(stats) => stats,
+            None => return,
+        };
+
         self.output = self
             .output
             .iter()
-            .map(|x: &f64| (x - output_mean) / output_std_dev)
+            .map(|y| y * stats.output_stats.std_dev + stats.output_stats.mean)
             .collect();
+
+        for row in self.input.iter_mut() {
+            for (feature, value) in row.iter_mut().enumerate() {
+                let column_stats: &Statistics = &stats.feature_stats[feature];
+                *value = *value * column_stats.std_dev + column_stats.mean;
+            }
+        }
+    }
+
+    /// Splits the dataset into disjoint `DataSet`s, one per entry in `ratios`.
+    ///
+    /// Ratios need not sum to 1.0; each split receives `ratio / Σratios` of the rows,
+    /// with the final split taking the remainder so rounding never drops a row.
+    ///
+    /// # Arguments
+    ///
+    /// * `ratios`: The relative size of each split, e.g. `&[0.8, 0.1, 0.1]` for a
+    ///   train/validation/test split.
+    /// * `shuffle_seed`: When `Some`, rows are shuffled deterministically with this
+    ///   seed before splitting; when `None`, rows keep their original order.
+    ///
+    /// # Example usage
+    ///
+    /// ```rust
+    /// let data = DataSet::new(Path::new("dataset.csv"));
+    /// let splits = data.split(&[0.8, 0.2], Some(42));
+    /// let (train, validation) = (&splits[0], &splits[1]);
+    /// ```
+    pub fn split(&self, ratios: &[f64], shuffle_seed: Option<u64>) -> Vec<DataSet> {
+        let mut indices: Vec<usize> = (0..self.size).collect();
+
+        if let Some(seed) = shuffle_seed {
+            shuffle(&mut indices, seed);
+        }
+
+        let total_ratio: f64 = ratios.iter().sum();
+        let mut splits: Vec<DataSet> = Vec::with_capacity(ratios.len());
+        let mut cursor: usize = 0;
+
+        for (i, ratio) in ratios.iter().enumerate() {
+            // The last split takes whatever remains, so rounding never drops a row. Every
+            // other split is clamped to what's left so rounding up can never push `cursor`
+            // past `self.size` before that last split is reached.
+            let count: usize = if i + 1 == ratios.len() {
+                self.size - cursor
+            } else {
+                (((ratio / total_ratio) * self.size as f64).round() as usize).min(self.size - cursor)
+            };
+
+            let mut input: Vec<Vec<f64>> = Vec::with_capacity(count);
+            let mut output: Vec<f64> = Vec::with_capacity(count);
+
+            for &index in &indices[cursor..cursor + count] {
+                input.push(self.input[index].clone());
+                output.push(self.output[index]);
+            }
+
+            splits.push(DataSet {
+                size: input.len(),
+                input,
+                output,
+                n_features: self.n_features,
+                normalization: None,
+            });
+
+            cursor += count;
+        }
+
+        splits
     }
+}
 
+/// Normalizes a single feature row against previously-fitted `stats`, e.g. to scale a
+/// new prediction input the same way the training data was scaled.
+fn normalize_row(stats: &DataSetNormalization, row: &[f64]) -> Vec<f64> {
+    row.iter()
+        .zip(stats.feature_stats.iter())
+        .map(|(value, column_stats)| (value - column_stats.mean) / column_stats.std_dev)
+        .collect()
 }
 
+/// Shuffles `indices` in place via Fisher-Yates, driven by a small deterministic LCG
+/// seeded from `seed` so splits are reproducible without pulling in a `rand` dependency.
+fn shuffle(indices: &mut [usize], seed: u64) {
+    let mut state: u64 = seed;
+
+    for i in (1..indices.len()).rev() {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let j: usize = (state >> 33) as usize % (i + 1);
+        indices.swap(i, j);
+    }
+}
@@ -1,36 +1,37 @@
 use crate::libs::dataset::lineal_dataset::DataSet;
 
-/// Calculates the mean squared error (MSE) of a linear regression model on a given dataset.
+use super::optimizer::Optimizer;
+
+/// Calculates the L2-regularized mean squared error (MSE) of a linear regression model on a given dataset.
 
 /// Args:
 /// * `b`: The bias term of the model.
-/// * `w`: The weight vector of the model.
+/// * `w`: The weight vector of the model, one entry per feature.
 /// * `data`: A DataSet object containing the input and output data points.
+/// * `l2_lambda`: The ridge penalty strength; the bias is never penalized.
 
 /// Returns:
-/// The mean squared error of the model on the given dataset.
+/// The mean squared error plus the `(l2_lambda/(2n))·Σwⱼ²` ridge penalty.
 
 /// Example usage
 /// ```rust
-/// let input_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// let output_data = vec![2.0, 4.0, 6.0, 8.0, 10.0];
-/// let data = DataSet::new(input_data, output_data);
+/// let data = DataSet::new(Path::new("dataset.csv"));
 ///
 /// let b = 1.0;
-/// let w = 2.0;
+/// let w = vec![2.0];
 ///
-/// let cost = calculate_cost(&b, &w, &data);
+/// let cost = calculate_cost(&b, &w, &data, 0.0);
 ///
 /// println!("Mean squared error: {}", cost);
 /// ```
 
-pub fn calculate_cost(b: &f64, w: &f64, data: &DataSet) -> f64 {
+pub fn calculate_cost(b: &f64, w: &[f64], data: &DataSet, l2_lambda: f64) -> f64 {
     // Calculate squared errors for each data point
     let squared_errors: f64 = data
         .output
         .iter()
         .zip(data.input.iter())
-        .map(|(x, y)| (b + (w * x) - y).powf(2.0))
+        .map(|(y, x)| (predict_row(b, w, x) - y).powf(2.0))
         .sum();
 
     // Handle potential division by zero
@@ -38,78 +39,148 @@ pub fn calculate_cost(b: &f64, w: &f64, data: &DataSet) -> f64 {
         return f64::NAN; // Or return a default value or panic, depending on your error handling strategy
     }
 
-    // Calculate mean squared error
-    return squared_errors / (2.0 * data.size as f64);
+    let penalty: f64 = l2_lambda * w.iter().map(|wj| wj.powf(2.0)).sum::<f64>();
+
+    // Calculate mean squared error plus the ridge penalty.
+    return (squared_errors + penalty) / (2.0 * data.size as f64);
+}
+
+/// Computes `ŷ = b + Σⱼ wⱼ·xⱼ` for a single feature row.
+fn predict_row(b: &f64, w: &[f64], x: &[f64]) -> f64 {
+    w.iter().zip(x.iter()).fold(*b, |acc, (wj, xj)| acc + wj * xj)
 }
 
-/// Performs gradient descent to optimize the bias and weight of a linear model.
+/// The optional knobs for `gradient_descent`, bundled so the function itself doesn't
+/// accumulate one positional parameter per feature (regularization, optimizer choice,
+/// early stopping, ...).
+pub struct GradientDescentOptions<'a> {
+    /// The ridge penalty strength; the bias is never penalized.
+    pub l2_lambda: f64,
+    /// The `Optimizer` used to turn gradients into parameter updates.
+    pub optimizer: &'a mut dyn Optimizer,
+    /// When `Some` together with `patience`, training stops early once the validation
+    /// cost fails to improve for `patience` consecutive epochs.
+    pub validation_data: Option<&'a DataSet>,
+    /// The number of consecutive non-improving epochs tolerated before stopping early.
+    /// Ignored unless `validation_data` is also `Some`.
+    pub patience: Option<u32>,
+}
+
+/// Performs gradient descent to optimize the bias and weights of a linear model.
 ///
-/// Iterates through a specified number of iterations, adjusting the bias and weight
-/// based on the calculated gradients to minimize the error on the given training data.
+/// Iterates through a specified number of iterations, computing the gradient of the
+/// cost at each step and handing it to `options.optimizer` to update the bias and weights.
 ///
 /// # Arguments
 ///
 /// * `num_iterations`: The number of iterations to perform gradient descent.
-/// * `learning_rate`: The step size used to update the bias and weight in each iteration.
 /// * `initial_bias`: The initial value for the bias term.
-/// * `initial_weight`: The initial value for the weight term.
+/// * `initial_weights`: The initial values for the weight vector.
 /// * `training_data`: A `DataSet` containing the input and output data for training.
+/// * `options`: The optional knobs — regularization, optimizer, and early stopping.
 ///
 /// # Returns
 ///
-/// A tuple containing the optimized bias and weight values.
+/// A tuple of the optimized bias, the optimized weight vector, and the training
+/// cost recorded at the end of every epoch.
 ///
 /// # Example usage
 ///
 /// ```rust
-/// let input_data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// let output_data = vec![2.0, 4.0, 5.0, 4.0, 5.0];
-/// let data = DataSet::new(input_data, output_data);
+/// let data = DataSet::new(Path::new("dataset.csv"));
 ///
 /// let initial_bias = 0.0;
-/// let initial_weight = 0.0;
+/// let initial_weights = vec![0.0; data.n_features];
+/// let mut optimizer = Sgd::new(0.01, 0.0);
 ///
-/// let (optimized_bias, optimized_weight) = gradient_descent(
+/// let (optimized_bias, optimized_weights, cost_history) = gradient_descent(
 ///     100, // Number of iterations
-///     0.01, // Learning rate
 ///     &initial_bias,
-///     &initial_weight,
+///     &initial_weights,
 ///     &data,
+///     GradientDescentOptions {
+///         l2_lambda: 0.0,
+///         optimizer: &mut optimizer,
+///         validation_data: None,
+///         patience: None,
+///     },
 /// );
 ///
 /// println!("Optimized bias: {}", optimized_bias);
-/// println!("Optimized weight: {}", optimized_weight);
+/// println!("Optimized weights: {:?}", optimized_weights);
 /// ```
 pub fn gradient_descent(
     num_iterations: u32,
-    learning_rate: f64,
     initial_bias: &f64,
-    initial_weight: &f64,
+    initial_weights: &[f64],
     training_data: &DataSet,
-) -> (f64, f64) {
-    let mut bias: f64 = *initial_bias;
-    let mut weight: f64 = *initial_weight;
+    options: GradientDescentOptions,
+) -> (f64, Vec<f64>, Vec<f64>) {
+    let GradientDescentOptions {
+        l2_lambda,
+        optimizer,
+        validation_data,
+        patience,
+    } = options;
+
+    // `params[0]` is the bias, `params[1..]` are the weights, so a single optimizer
+    // instance can update both with one call per iteration.
+    let mut params: Vec<f64> = std::iter::once(*initial_bias)
+        .chain(initial_weights.iter().copied())
+        .collect();
+
+    let mut cost_history: Vec<f64> = Vec::new();
+    let mut best_validation_cost: f64 = f64::INFINITY;
+    let mut epochs_without_improvement: u32 = 0;
 
     for _ in 0..num_iterations {
         let num_data_points: f64 = training_data.size as f64;
+        let bias: f64 = params[0];
+        let weights: &[f64] = &params[1..];
+
+        let mut bias_gradient: f64 = 0.0;
+        let mut weight_gradients: Vec<f64> = vec![0.0; weights.len()];
+
+        for (y, x) in training_data.output.iter().zip(training_data.input.iter()) {
+            let error: f64 = predict_row(&bias, weights, x) - y;
+
+            bias_gradient += error;
+            for (gradient, xj) in weight_gradients.iter_mut().zip(x.iter()) {
+                *gradient += error * xj;
+            }
+        }
+
+        // The bias is never penalized; only the weight gradients gain the `l2_lambda·wⱼ/n`
+        // term, matching the `(l2_lambda/(2n))·Σwⱼ²` penalty in `calculate_cost` so the
+        // gradient step is actually the derivative of the reported cost.
+        let grads: Vec<f64> = std::iter::once(bias_gradient / num_data_points)
+            .chain(
+                weight_gradients
+                    .iter()
+                    .zip(weights.iter())
+                    .map(|(g, wj)| g / num_data_points + l2_lambda * wj / num_data_points),
+            )
+            .collect();
+
+        optimizer.step(&mut params, &grads);
+
+        cost_history.push(calculate_cost(&params[0], &params[1..], training_data, l2_lambda));
+
+        if let (Some(validation_data), Some(patience)) = (validation_data, patience) {
+            let validation_cost: f64 =
+                calculate_cost(&params[0], &params[1..], validation_data, l2_lambda);
 
-        let bias_gradient: f64 = training_data
-            .output
-            .iter()
-            .zip(training_data.input.iter())
-            .map(|(x, y)| bias + weight * x - y)
-            .sum();
-
-        let weight_gradient: f64 = training_data
-            .output
-            .iter()
-            .zip(training_data.input.iter())
-            .map(|(x, y)| (bias + weight * x - y) * x)
-            .sum();
-
-        bias -= learning_rate * bias_gradient / num_data_points;
-        weight -= learning_rate * weight_gradient / num_data_points;
+            if validation_cost < best_validation_cost {
+                best_validation_cost = validation_cost;
+                epochs_without_improvement = 0;
+            } else {
+                epochs_without_improvement += 1;
+                if epochs_without_improvement >= patience {
+                    break;
+                }
+            }
+        }
     }
 
-    (bias, weight)
+    (params[0], params[1..].to_vec(), cost_history)
 }
@@ -0,0 +1,137 @@
+/// An in-place parameter update rule for gradient-based training.
+///
+/// Implementors own whatever per-parameter state they need (velocity, moment
+/// estimates, ...) so the training loop can stay oblivious to which rule is in use.
+pub trait Optimizer {
+    /// Updates `params` in place given the gradient computed for this step.
+    fn step(&mut self, params: &mut [f64], grads: &[f64]);
+}
+
+/// Stochastic gradient descent with classical momentum.
+///
+/// `v ← μv − lr·g; θ ← θ + v`
+pub struct Sgd {
+    pub learning_rate: f64,
+    pub momentum: f64,
+    velocity: Vec<f64>,
+}
+
+impl Sgd {
+    /// Creates a new `Sgd` optimizer with an empty velocity buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `learning_rate`: The step size applied to the gradient.
+    /// * `momentum`: The fraction of the previous velocity retained each step (0.0 disables momentum).
+    pub fn new(learning_rate: f64, momentum: f64) -> Self {
+        Self {
+            learning_rate,
+            momentum,
+            velocity: Vec::new(),
+        }
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&mut self, params: &mut [f64], grads: &[f64]) {
+        if self.velocity.len() != params.len() {
+            self.velocity = vec![0.0; params.len()];
+        }
+
+        for ((param, grad), velocity) in
+            params.iter_mut().zip(grads.iter()).zip(self.velocity.iter_mut())
+        {
+            *velocity = self.momentum * *velocity - self.learning_rate * grad;
+            *param += *velocity;
+        }
+    }
+}
+
+/// Adam: adaptive moment estimation.
+///
+/// Maintains first and second moment estimates of the gradient, bias-corrects
+/// them, and scales the learning rate per parameter.
+pub struct Adam {
+    pub lr: f64,
+    pub beta1: f64,
+    pub beta2: f64,
+    pub eps: f64,
+    m: Vec<f64>,
+    s: Vec<f64>,
+    t: i32,
+}
+
+impl Adam {
+    /// Creates a new `Adam` optimizer with empty moment estimates.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr`: The base learning rate.
+    /// * `beta1`: The decay rate for the first moment estimate.
+    /// * `beta2`: The decay rate for the second moment estimate.
+    /// * `eps`: A small constant added to the denominator for numerical stability.
+    pub fn new(lr: f64, beta1: f64, beta2: f64, eps: f64) -> Self {
+        Self {
+            lr,
+            beta1,
+            beta2,
+            eps,
+            m: Vec::new(),
+            s: Vec::new(),
+            t: 0,
+        }
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self, params: &mut [f64], grads: &[f64]) {
+        if self.m.len() != params.len() {
+            self.m = vec![0.0; params.len()];
+            self.s = vec![0.0; params.len()];
+        }
+
+        self.t += 1;
+
+        for i in 0..params.len() {
+            self.m[i] = self.beta1 * self.m[i] + (1.0 - self.beta1) * grads[i];
+            self.s[i] = self.beta2 * self.s[i] + (1.0 - self.beta2) * grads[i].powf(2.0);
+
+            let m_hat: f64 = self.m[i] / (1.0 - self.beta1.powi(self.t));
+            let s_hat: f64 = self.s[i] / (1.0 - self.beta2.powi(self.t));
+
+            params[i] -= self.lr * m_hat / (s_hat.sqrt() + self.eps);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drives `optimizer` to minimize the toy quadratic `f(x) = (x - target)²` per
+    /// parameter, whose gradient is `2(x - target)`.
+    fn minimize_quadratic(optimizer: &mut dyn Optimizer, target: f64, steps: u32) -> f64 {
+        let mut params: Vec<f64> = vec![0.0];
+
+        for _ in 0..steps {
+            let grads: Vec<f64> = params.iter().map(|x| 2.0 * (x - target)).collect();
+            optimizer.step(&mut params, &grads);
+        }
+
+        params[0]
+    }
+
+    #[test]
+    fn sgd_with_momentum_converges() {
+        let mut optimizer = Sgd::new(0.1, 0.9);
+        let x = minimize_quadratic(&mut optimizer, 3.0, 200);
+        assert!((x - 3.0).abs() < 1e-3, "expected ~3.0, got {x}");
+    }
+
+    #[test]
+    fn adam_converges() {
+        let mut optimizer = Adam::new(0.1, 0.9, 0.999, 1e-8);
+        let x = minimize_quadratic(&mut optimizer, 3.0, 200);
+        assert!((x - 3.0).abs() < 1e-3, "expected ~3.0, got {x}");
+    }
+}
@@ -0,0 +1,91 @@
+/// Solves the linear system `a·x = b` via Gaussian elimination with partial pivoting.
+///
+/// `a` must be square. Returns `None` if `a` is singular (or near-singular) and no
+/// unique solution exists.
+///
+/// # Example usage
+///
+/// ```rust
+/// let a = vec![vec![2.0, 1.0], vec![1.0, 3.0]];
+/// let b = vec![5.0, 10.0];
+/// let x = solve(&a, &b).expect("singular system");
+/// ```
+pub fn solve(a: &[Vec<f64>], b: &[f64]) -> Option<Vec<f64>> {
+    let n: usize = a.len();
+    // Augment `a` with `b` so elimination updates both in lockstep.
+    let mut augmented: Vec<Vec<f64>> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(row, &rhs)| {
+            let mut row: Vec<f64> = row.clone();
+            row.push(rhs);
+            row
+        })
+        .collect();
+
+    for col in 0..n {
+        // Partial pivot: swap in the row with the largest magnitude in this column.
+        let pivot_row: usize = (col..n).max_by(|&i, &j| {
+            augmented[i][col]
+                .abs()
+                .partial_cmp(&augmented[j][col].abs())
+                .unwrap()
+        })?;
+
+        if augmented[pivot_row][col].abs() < 1e-12 {
+            return None; // Singular matrix.
+        }
+
+        augmented.swap(col, pivot_row);
+
+        for row in (col + 1)..n {
+            let factor: f64 = augmented[row][col] / augmented[col][col];
+            for k in col..=n {
+                augmented[row][k] -= factor * augmented[col][k];
+            }
+        }
+    }
+
+    // Back-substitution.
+    let mut x: Vec<f64> = vec![0.0; n];
+    for row in (0..n).rev() {
+        let mut sum: f64 = augmented[row][n];
+        for col in (row + 1)..n {
+            sum -= augmented[row][col] * x[col];
+        }
+        x[row] = sum / augmented[row][row];
+    }
+
+    Some(x)
+}
+
+/// Inverts a square matrix by solving `a·x = eᵢ` for each standard basis vector `eᵢ`.
+///
+/// Returns `None` if `a` is singular.
+///
+/// # Example usage
+///
+/// ```rust
+/// let a = vec![vec![2.0, 0.0], vec![0.0, 4.0]];
+/// let inverse = invert(&a).expect("singular matrix");
+/// ```
+pub fn invert(a: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n: usize = a.len();
+    let mut columns: Vec<Vec<f64>> = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let mut basis: Vec<f64> = vec![0.0; n];
+        basis[i] = 1.0;
+        columns.push(solve(a, &basis)?);
+    }
+
+    // `columns[i]` holds the i-th column of the inverse; transpose into rows.
+    let mut inverse: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+    for (col, values) in columns.iter().enumerate() {
+        for (row, &value) in values.iter().enumerate() {
+            inverse[row][col] = value;
+        }
+    }
+
+    Some(inverse)
+}
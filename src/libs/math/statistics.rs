@@ -1,4 +1,5 @@
-struct Statistics {
+#[derive(Debug, Clone)]
+pub struct Statistics {
     pub mean: f64,
     pub variance: f64,
     pub std_dev: f64,
@@ -62,7 +63,7 @@ impl Statistics {
 ///
 /// # Returns
 ///
-/// The median value of the vector.
+/// The median value of the vector, or `NaN` if `data` is empty.
 ///
 /// # Example usage
 ///
@@ -72,6 +73,10 @@ impl Statistics {
 /// println!("Median: {}", median_value); // Output: Median: 3.0
 /// ```
 fn median(data: &Vec<f64>) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+
     let mut data: Vec<f64> = data.clone();
 
     data.sort_by(|a: &f64, b: &f64| a.partial_cmp(b).unwrap());
@@ -93,7 +98,8 @@ fn median(data: &Vec<f64>) -> f64 {
 ///
 /// # Returns
 ///
-/// The mode of the vector, or an arbitrary value if there is no unique mode.
+/// The mode of the vector, or an arbitrary value if there is no unique mode, or `NaN`
+/// if `data` is empty.
 ///
 /// # Example usage
 ///
@@ -103,6 +109,10 @@ fn median(data: &Vec<f64>) -> f64 {
 /// println!("Mode: {}", mode_value); // Output: Mode: 3.0
 /// ```
 fn mode(data: &Vec<f64>) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+
     let mut data = data.clone();
 
     data.sort_by(|a, b| a.partial_cmp(b).unwrap());
@@ -124,5 +134,93 @@ fn mode(data: &Vec<f64>) -> f64 {
             current_count = 1;
         }
     }
+
+    // The loop only updates `mode` on a value transition, so the final run never gets
+    // compared against `max_count` unless we check it once more here.
+    if current_count > max_count {
+        mode = current;
+    }
+
     mode
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_of_trailing_run() {
+        let data = vec![1.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0];
+        assert_eq!(mode(&data), 3.0);
+    }
+
+    #[test]
+    fn mode_of_leading_run() {
+        let data = vec![1.0, 1.0, 1.0, 2.0, 2.0, 3.0];
+        assert_eq!(mode(&data), 1.0);
+    }
+
+    #[test]
+    fn mode_and_median_of_empty_are_nan() {
+        let data: Vec<f64> = Vec::new();
+        assert!(mode(&data).is_nan());
+        assert!(median(&data).is_nan());
+    }
+}
+
+/// Calculates the covariance between two equal-length vectors of `f64` numbers.
+///
+/// # Arguments
+///
+/// * `a`: The first vector of values.
+/// * `b`: The second vector of values.
+///
+/// # Returns
+///
+/// The covariance `Σ(aᵢ−ā)(bᵢ−b̄) / n`.
+///
+/// # Example usage
+///
+/// ```rust
+/// let a = vec![1.0, 2.0, 3.0];
+/// let b = vec![2.0, 4.0, 6.0];
+/// let cov = covariance(&a, &b);
+/// println!("Covariance: {}", cov);
+/// ```
+pub fn covariance(a: &[f64], b: &[f64]) -> f64 {
+    let n: f64 = a.len() as f64;
+    let mean_a: f64 = a.iter().sum::<f64>() / n;
+    let mean_b: f64 = b.iter().sum::<f64>() / n;
+
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - mean_a) * (y - mean_b))
+        .sum::<f64>()
+        / n
+}
+
+/// Calculates the Pearson correlation coefficient between two equal-length vectors.
+///
+/// # Arguments
+///
+/// * `a`: The first vector of values.
+/// * `b`: The second vector of values.
+///
+/// # Returns
+///
+/// The correlation `covariance(a, b) / (std_dev(a)·std_dev(b))`, in `[-1.0, 1.0]`.
+///
+/// # Example usage
+///
+/// ```rust
+/// let a = vec![1.0, 2.0, 3.0];
+/// let b = vec![2.0, 4.0, 6.0];
+/// let corr = correlation(&a, &b);
+/// println!("Correlation: {}", corr);
+/// ```
+pub fn correlation(a: &[f64], b: &[f64]) -> f64 {
+    let std_dev_a: f64 = Statistics::new(&a.to_vec()).std_dev;
+    let std_dev_b: f64 = Statistics::new(&b.to_vec()).std_dev;
+
+    covariance(a, b) / (std_dev_a * std_dev_b)
+}
@@ -1,18 +1,45 @@
-use super::gradient::{calculate_cost, gradient_descent};
+use super::gradient::{calculate_cost, gradient_descent, GradientDescentOptions};
 use crate::libs::dataset::lineal_dataset::DataSet;
+use crate::libs::gradient_descents::optimizer::{Optimizer, Sgd};
+use crate::libs::math::linalg::{invert, solve};
 
 use super::model::Model;
 
+/// Selects how `LinealRegression::fit` computes its parameters.
+pub enum FitMethod {
+    /// Iteratively minimizes the cost via `gradient_descent`, driven by `optimizer`.
+    GradientDescent,
+    /// Solves the normal equations directly, with no learning rate to tune.
+    OrdinaryLeastSquares,
+}
+
 pub struct LinealRegressionOptions {
     pub epochs: u32,
-    pub learning_rate: f64,
-    pub nornalize: bool,
+    pub normalize: bool,
+    pub method: FitMethod,
+    /// Update rule used by `FitMethod::GradientDescent`; unused for `OrdinaryLeastSquares`.
+    pub optimizer: Box<dyn Optimizer>,
+    /// Ridge (L2) penalty strength; the bias is never penalized. 0.0 disables regularization.
+    pub l2_lambda: f64,
+    /// Held-out split used for early stopping during `FitMethod::GradientDescent`.
+    pub validation_data: Option<DataSet>,
+    /// Number of consecutive non-improving epochs tolerated before stopping early.
+    /// Ignored unless `validation_data` is also set.
+    pub patience: Option<u32>,
 }
 
 pub struct LinealRegression {
     pub b: f64,
-    pub w: f64,
+    pub w: Vec<f64>,
     pub cost: f64,
+    /// Fraction of the output variance explained by the model (1.0 is a perfect fit).
+    pub r_squared: f64,
+    /// Standard error of each fitted weight in `w`.
+    pub se_w: Vec<f64>,
+    /// Standard error of the fitted bias `b`.
+    pub se_b: f64,
+    /// Training cost recorded at the end of every `FitMethod::GradientDescent` epoch.
+    pub cost_history: Vec<f64>,
     pub training_data: DataSet,
     pub options: LinealRegressionOptions,
 }
@@ -30,18 +57,105 @@ impl LinealRegression {
     /// A new `LinealRegression` instance with initial model parameters set to 0.0.
     pub fn new(training_data: DataSet, options: LinealRegressionOptions) -> Self {
         // Initialize model parameters and store training data and options.
+        let n_features: usize = training_data.n_features;
+
         Self {
-            b: 0.0,    // Initial bias term
-            w: 0.0,    // Initial weight term
-            cost: 0.0, // Initial cost
+            b: 0.0,                        // Initial bias term
+            w: vec![0.0; n_features],      // Initial weight vector
+            cost: 0.0,                     // Initial cost
+            r_squared: 0.0,
+            se_w: vec![0.0; n_features],
+            se_b: 0.0,
+            cost_history: Vec::new(),
             training_data,
             options,
         }
     }
+
+    /// Solves the normal equations `(XᵀX)β = Xᵀy` in closed form, where `X` is the
+    /// design matrix with a leading column of ones for the intercept.
+    ///
+    /// # Returns
+    ///
+    /// The `(bias, weights)` pair that minimizes the squared error exactly, with no
+    /// iteration and no learning rate to tune. Returns `None` if `XᵀX` is singular.
+    fn fit_ols(&self) -> Option<(f64, Vec<f64>)> {
+        let data: &DataSet = &self.training_data;
+        let n_params: usize = data.n_features + 1;
+
+        // Build Xᵀy and XᵀX directly, without materializing the design matrix.
+        let mut xtx: Vec<Vec<f64>> = vec![vec![0.0; n_params]; n_params];
+        let mut xty: Vec<f64> = vec![0.0; n_params];
+
+        for (y, features) in data.output.iter().zip(data.input.iter()) {
+            // Row of the design matrix: the intercept's implicit 1.0 followed by the features.
+            let row: Vec<f64> = std::iter::once(1.0).chain(features.iter().copied()).collect();
+
+            for i in 0..n_params {
+                xty[i] += row[i] * y;
+                for j in 0..n_params {
+                    xtx[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        let beta: Vec<f64> = solve(&xtx, &xty)?;
+        let b: f64 = beta[0];
+        let w: Vec<f64> = beta[1..].to_vec();
+
+        Some((b, w))
+    }
+
+    /// Recomputes `r_squared`, `se_w`, and `se_b` from the currently fitted `b` and `w`.
+    ///
+    /// Called after every `fit()` so gradient descent and OLS report the same
+    /// diagnostics regardless of which method produced the parameters.
+    fn update_diagnostics(&mut self) {
+        let data: &DataSet = &self.training_data;
+        let n: f64 = data.size as f64;
+        let n_params: usize = data.n_features + 1;
+
+        let y_mean: f64 = data.output.iter().sum::<f64>() / n;
+
+        let ss_res: f64 = data
+            .output
+            .iter()
+            .zip(data.input.iter())
+            .map(|(y, x)| (y - predict_row(&self.b, &self.w, x)).powf(2.0))
+            .sum();
+        let ss_tot: f64 = data.output.iter().map(|y| (y - y_mean).powf(2.0)).sum();
+
+        self.r_squared = 1.0 - ss_res / ss_tot;
+
+        // Standard errors come from the diagonal of (XᵀX)⁻¹ scaled by the residual
+        // variance, the same normal-equations matrix used by `fit_ols`.
+        let mut xtx: Vec<Vec<f64>> = vec![vec![0.0; n_params]; n_params];
+        for features in data.input.iter() {
+            let row: Vec<f64> = std::iter::once(1.0).chain(features.iter().copied()).collect();
+            for i in 0..n_params {
+                for j in 0..n_params {
+                    xtx[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        let residual_variance: f64 = ss_res / (n - n_params as f64);
+        if let Some(xtx_inv) = invert(&xtx) {
+            self.se_b = (residual_variance * xtx_inv[0][0]).sqrt();
+            self.se_w = (1..n_params)
+                .map(|i| (residual_variance * xtx_inv[i][i]).sqrt())
+                .collect();
+        }
+    }
 }
 
-impl Model<f64, f64> for LinealRegression {
-    /// Trains the linear regression model using gradient descent.
+/// Computes `ŷ = b + Σⱼ wⱼ·xⱼ` for a single feature row.
+fn predict_row(b: &f64, w: &[f64], x: &[f64]) -> f64 {
+    w.iter().zip(x.iter()).fold(*b, |acc, (wj, xj)| acc + wj * xj)
+}
+
+impl<'a> Model<&'a [f64], f64> for LinealRegression {
+    /// Trains the linear regression model using the configured `FitMethod`.
     ///
     /// # Arguments
     ///
@@ -59,22 +173,42 @@ impl Model<f64, f64> for LinealRegression {
     /// model.fit().expect("Failed to train model");
     /// ```
     fn fit(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if self.options.nornalize {
+        if self.options.normalize {
             // Normalize training data
             self.training_data.normalize();
         }
 
-        let (b, w) = gradient_descent(
-            self.options.epochs,
-            self.options.learning_rate,
-            &self.b,
-            &self.w,
-            &self.training_data,
-        );
+        match self.options.method {
+            FitMethod::GradientDescent => {
+                let (b, w, cost_history) = gradient_descent(
+                    self.options.epochs,
+                    &self.b,
+                    &self.w,
+                    &self.training_data,
+                    GradientDescentOptions {
+                        l2_lambda: self.options.l2_lambda,
+                        optimizer: self.options.optimizer.as_mut(),
+                        validation_data: self.options.validation_data.as_ref(),
+                        patience: self.options.patience,
+                    },
+                );
 
-        self.b = b;
-        self.w = w;
-        self.cost = calculate_cost(&self.b, &self.w, &self.training_data);
+                self.b = b;
+                self.w = w;
+                self.cost_history = cost_history;
+            }
+            FitMethod::OrdinaryLeastSquares => {
+                let (b, w) = self
+                    .fit_ols()
+                    .ok_or("OLS normal equations are singular for this dataset")?;
+
+                self.b = b;
+                self.w = w;
+            }
+        }
+
+        self.cost = calculate_cost(&self.b, &self.w, &self.training_data, self.options.l2_lambda);
+        self.update_diagnostics();
 
         Ok(())
     }
@@ -84,7 +218,7 @@ impl Model<f64, f64> for LinealRegression {
     /// # Arguments
     ///
     /// * `self`: A mutable reference to the `LinealRegression` object.
-    /// * `value`: The input value for which to make a prediction.
+    /// * `value`: The feature vector to predict an output for.
     ///
     /// # Returns
     ///
@@ -97,14 +231,77 @@ impl Model<f64, f64> for LinealRegression {
     /// let mut model = LinealRegression::new(...);
     /// model.fit().expect("Failed to train model");
     ///
-    /// let input_value = 5.0;
-    /// let prediction = model.predict(input_value).expect("Failed to make prediction");
+    /// let prediction = model.predict(&[5.0]).expect("Failed to make prediction");
     /// println!("Predicted value: {}", prediction);
     /// ```
-    fn predict(&mut self, value: f64) -> Result<f64, Box<dyn std::error::Error>> {
+    fn predict(&mut self, value: &'a [f64]) -> Result<f64, Box<dyn std::error::Error>> {
         // Perform necessary model-specific calculations
-        let prediction = self.w.mul_add(value, self.b);
+        let prediction = predict_row(&self.b, &self.w, value);
 
         Ok(prediction)
     }
 }
+
+/// Sweeps `l2_lambda` over `[min_reg, max_reg]` and returns the value minimizing the
+/// mean squared error on `validation_data`.
+///
+/// Trains one `LinealRegression` per candidate lambda on `training_data` with plain
+/// (momentum-free) `Sgd`, evaluating each fit against the held-out `validation_data`.
+///
+/// # Arguments
+///
+/// * `training_data`: The `DataSet` used to fit each candidate model.
+/// * `validation_data`: The held-out `DataSet` used to score each candidate lambda.
+/// * `epochs`: Number of gradient descent iterations per candidate.
+/// * `learning_rate`: Learning rate used for every candidate's `Sgd` optimizer.
+/// * `min_reg`: The lower bound of the lambda sweep.
+/// * `max_reg`: The upper bound of the lambda sweep.
+/// * `steps`: The number of intervals to divide `[min_reg, max_reg]` into.
+///
+/// # Returns
+///
+/// The `l2_lambda` that achieved the lowest validation MSE.
+pub fn tune_regularization(
+    training_data: &DataSet,
+    validation_data: &DataSet,
+    epochs: u32,
+    learning_rate: f64,
+    min_reg: f64,
+    max_reg: f64,
+    steps: u32,
+) -> f64 {
+    let mut best_lambda: f64 = min_reg;
+    let mut best_validation_cost: f64 = f64::INFINITY;
+
+    for step in 0..=steps {
+        let lambda: f64 = min_reg + (max_reg - min_reg) * (step as f64 / steps.max(1) as f64);
+
+        let mut model: LinealRegression = LinealRegression::new(
+            training_data.clone(),
+            LinealRegressionOptions {
+                epochs,
+                normalize: false,
+                method: FitMethod::GradientDescent,
+                optimizer: Box::new(Sgd::new(learning_rate, 0.0)),
+                l2_lambda: lambda,
+                validation_data: None,
+                patience: None,
+            },
+        );
+
+        if model.fit().is_err() {
+            continue;
+        }
+
+        // Score against the held-out split with no penalty, so the sweep compares
+        // candidates on plain MSE rather than the regularized training objective.
+        let validation_cost: f64 = calculate_cost(&model.b, &model.w, validation_data, 0.0);
+
+        if validation_cost < best_validation_cost {
+            best_validation_cost = validation_cost;
+            best_lambda = lambda;
+        }
+    }
+
+    best_lambda
+}
@@ -0,0 +1,170 @@
+use crate::libs::dataset::lineal_dataset::DataSet;
+use crate::libs::math::linalg::solve;
+
+use super::model::Model;
+
+/// Weights below this floor would blow up the IRLS normal equations, since the
+/// working response divides by `W = p(1-p)`.
+const MIN_IRLS_WEIGHT: f64 = 1e-10;
+
+pub struct LogisticRegressionOptions {
+    /// Maximum number of IRLS iterations to run.
+    pub max_iterations: u32,
+}
+
+pub struct LogisticRegression {
+    pub b: f64,
+    pub w: Vec<f64>,
+    pub training_data: DataSet,
+    pub options: LogisticRegressionOptions,
+}
+
+impl LogisticRegression {
+    /// Creates a new instance of a logistic regression model.
+    ///
+    /// # Arguments
+    ///
+    /// * `training_data`: The `DataSet` containing the training data to be used for model fitting.
+    /// * `options`: The `LogisticRegressionOptions` specifying configuration options for the model.
+    ///
+    /// # Returns
+    ///
+    /// A new `LogisticRegression` instance with initial model parameters set to 0.0.
+    pub fn new(training_data: DataSet, options: LogisticRegressionOptions) -> Self {
+        let n_features: usize = training_data.n_features;
+
+        Self {
+            b: 0.0,
+            w: vec![0.0; n_features],
+            training_data,
+            options,
+        }
+    }
+
+    /// Returns the linear predictor `z = b + Σⱼ wⱼ·xⱼ` for a feature row.
+    fn linear_predictor(&self, x: &[f64]) -> f64 {
+        self.w.iter().zip(x.iter()).fold(self.b, |acc, (wj, xj)| acc + wj * xj)
+    }
+
+    /// Returns the predicted probability `p = 1/(1+exp(-z))` for a feature row.
+    fn probability(&self, x: &[f64]) -> f64 {
+        1.0 / (1.0 + (-self.linear_predictor(x)).exp())
+    }
+
+    /// Predicts the binary class for a feature row, thresholding the probability at 0.5.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The feature vector to classify.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the predicted probability is at least 0.5, `false` otherwise.
+    pub fn predict_class(&self, value: &[f64]) -> bool {
+        self.probability(value) >= 0.5
+    }
+}
+
+impl<'a> Model<&'a [f64], f64> for LogisticRegression {
+    /// Fits the model via iteratively reweighted least squares (IRLS).
+    ///
+    /// Starting from `β = 0`, each iteration computes predicted probabilities `pᵢ`,
+    /// weights `Wᵢ = pᵢ(1−pᵢ)` (clamped away from zero), and a working response
+    /// `zᵢ = (b+Σ wⱼxᵢⱼ) + (yᵢ−pᵢ)/Wᵢ`, then solves the weighted normal equations
+    /// `β ← (XᵀWX)⁻¹XᵀWz`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Box<dyn std::error::Error>>`: Returns `Ok(())` on success,
+    ///   or an error boxed as `Box<dyn std::error::Error>` if the weighted normal
+    ///   equations are singular.
+    fn fit(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let data: &DataSet = &self.training_data;
+        let n_params: usize = data.n_features + 1;
+
+        // Rows of the design matrix, with a leading column of ones for the intercept.
+        let rows: Vec<Vec<f64>> = data
+            .input
+            .iter()
+            .map(|features: &Vec<f64>| {
+                std::iter::once(1.0).chain(features.iter().copied()).collect()
+            })
+            .collect();
+
+        for _ in 0..self.options.max_iterations {
+            let mut xtwx: Vec<Vec<f64>> = vec![vec![0.0; n_params]; n_params];
+            let mut xtwz: Vec<f64> = vec![0.0; n_params];
+
+            for (row, y) in rows.iter().zip(data.output.iter()) {
+                let features: &[f64] = &row[1..];
+                let linear: f64 = self.linear_predictor(features);
+                let p: f64 = 1.0 / (1.0 + (-linear).exp());
+                let weight: f64 = (p * (1.0 - p)).max(MIN_IRLS_WEIGHT);
+                let working_response: f64 = linear + (y - p) / weight;
+
+                for i in 0..n_params {
+                    xtwz[i] += row[i] * weight * working_response;
+                    for j in 0..n_params {
+                        xtwx[i][j] += row[i] * weight * row[j];
+                    }
+                }
+            }
+
+            let beta: Vec<f64> = solve(&xtwx, &xtwz)
+                .ok_or("IRLS weighted normal equations are singular for this dataset")?;
+
+            self.b = beta[0];
+            self.w = beta[1..].to_vec();
+        }
+
+        Ok(())
+    }
+
+    /// Predicts the probability of the positive class for a feature row.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The feature vector to predict a probability for.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<f64, Box<dyn std::error::Error>>`: The predicted probability.
+    fn predict(&mut self, value: &'a [f64]) -> Result<f64, Box<dyn std::error::Error>> {
+        Ok(self.probability(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovers_a_linearly_separable_boundary() {
+        let training_data = DataSet {
+            input: vec![
+                vec![0.0],
+                vec![1.0],
+                vec![2.0],
+                vec![8.0],
+                vec![9.0],
+                vec![10.0],
+            ],
+            output: vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            size: 6,
+            n_features: 1,
+            normalization: None,
+        };
+
+        let mut model = LogisticRegression::new(
+            training_data,
+            LogisticRegressionOptions { max_iterations: 25 },
+        );
+
+        model.fit().expect("IRLS should converge on a separable dataset");
+
+        assert!(!model.predict_class(&[0.0]));
+        assert!(!model.predict_class(&[2.0]));
+        assert!(model.predict_class(&[8.0]));
+        assert!(model.predict_class(&[10.0]));
+    }
+}
@@ -6,29 +6,83 @@ use libs::dataset::lineal_dataset::DataSet;
 
 use libs::models::model::Model;
 
-use libs::models::lineal_regression::{LinealRegression, LinealRegressionOptions};
-
+use libs::gradient_descents::optimizer::Adam;
+use libs::math::statistics::{correlation, covariance};
+use libs::models::lineal_regression::{
+    tune_regularization, FitMethod, LinealRegression, LinealRegressionOptions,
+};
+use libs::models::logistic_regression::{LogisticRegression, LogisticRegressionOptions};
 
 fn main() {
     println!("Hello, world!");
     let filename: &Path = Path::new("./assets/lineal_dataset.csv");
 
-    let mut training_data: DataSet = DataSet::new(filename);
+    let training_data: DataSet = DataSet::new(filename);
 
     println!("dataset length {}", training_data.output.len());
 
-    let _ = training_data.normalize();
+    // Hold out a validation split for early stopping and for tuning l2_lambda.
+    let splits: Vec<DataSet> = training_data.split(&[0.8, 0.2], Some(42));
+    let (train, validation) = (splits[0].clone(), splits[1].clone());
+
+    let best_lambda: f64 = tune_regularization(&train, &validation, 100, 0.01, 0.0, 1.0, 10);
+    println!("tuned l2_lambda {}", best_lambda);
 
     let mut lineal_regression: LinealRegression = LinealRegression::new(
-        training_data,
+        train,
         LinealRegressionOptions {
             epochs: 100,
-            learning_rate: 0.01,
             normalize: false,
+            method: FitMethod::GradientDescent,
+            optimizer: Box::new(Adam::new(0.01, 0.9, 0.999, 1e-8)),
+            l2_lambda: best_lambda,
+            validation_data: Some(validation),
+            patience: Some(5),
         },
     );
 
     let _ = lineal_regression.fit();
 
     println!("cost {}", lineal_regression.cost);
+    println!("r_squared {}", lineal_regression.r_squared);
+
+    // Restore the training data to its original units if `fit` normalized it; with
+    // `normalize: false` above this call leaves the data unchanged.
+    lineal_regression.training_data.denormalize();
+
+    if lineal_regression.training_data.n_features >= 2 {
+        let column_a: Vec<f64> = lineal_regression
+            .training_data
+            .input
+            .iter()
+            .map(|row| row[0])
+            .collect();
+        let column_b: Vec<f64> = lineal_regression
+            .training_data
+            .input
+            .iter()
+            .map(|row| row[1])
+            .collect();
+
+        println!("covariance {}", covariance(&column_a, &column_b));
+        println!("correlation {}", correlation(&column_a, &column_b));
+    }
+
+    // A small, linearly-separable toy dataset to exercise the logistic regression path.
+    let classification_data: DataSet = DataSet {
+        input: vec![vec![0.0], vec![1.0], vec![4.0], vec![5.0]],
+        output: vec![0.0, 0.0, 1.0, 1.0],
+        size: 4,
+        n_features: 1,
+        normalization: None,
+    };
+
+    let mut logistic_regression: LogisticRegression = LogisticRegression::new(
+        classification_data,
+        LogisticRegressionOptions { max_iterations: 10 },
+    );
+
+    let _ = logistic_regression.fit();
+
+    println!("predicted class for 4.5: {}", logistic_regression.predict_class(&[4.5]));
 }